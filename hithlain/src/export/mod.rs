@@ -0,0 +1,27 @@
+//! Structural netlist export, the synthesizable-output counterpart to the
+//! waveform-oriented `vcd` module: where `vcd` lowers an instantiated
+//! process to a `vcd_ast` for tracing, `export` lowers a `Circuit` to a
+//! structural netlist suitable for handing off to an external synthesis
+//! toolchain.
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::parse::ast::Variable;
+
+pub mod netlist;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum ExportError {
+    #[error("circuit `{0}` has no body to export")]
+    EmptyCircuit(String),
+
+    #[error("no circuit named `{0}`")]
+    UnknownSubCircuit(Variable),
+
+    #[error("no structural netlist equivalent for operator {0}")]
+    UnsupportedOperator(String),
+
+    #[error("failed to write netlist export")]
+    Io(#[source] std::io::Error),
+}
@@ -0,0 +1,226 @@
+//! Lowers a `Circuit` to a structural, synthesizable netlist by walking the
+//! flat instruction vector produced by `sim::compile`: bitwise `Binary`/`Not`
+//! instructions become primitive gate instances, arithmetic/shift/compare
+//! `Binary` instructions become `assign` statements (Verilog supports those
+//! operators directly), and `Custom` calls become sub-module instantiations.
+
+use crate::export::ExportError;
+use crate::parse::ast::{BinaryAction, Circuit, Variable};
+use crate::sim::compile::{compile_circuit, Instruction, Slot};
+
+fn gate_name(action: &BinaryAction) -> Option<&'static str> {
+    match action {
+        BinaryAction::And => Some("and"),
+        BinaryAction::Or => Some("or"),
+        BinaryAction::Nand => Some("nand"),
+        BinaryAction::Nor => Some("nor"),
+        BinaryAction::Xor => Some("xor"),
+        BinaryAction::Xnor => Some("xnor"),
+        _ => None,
+    }
+}
+
+/// Arithmetic/shift/compare ops have no single-primitive gate equivalent,
+/// but Verilog's `assign` supports all of them directly.
+fn operator_symbol(action: &BinaryAction) -> Option<&'static str> {
+    match action {
+        BinaryAction::Add => Some("+"),
+        BinaryAction::Sub => Some("-"),
+        BinaryAction::Mul => Some("*"),
+        BinaryAction::Shl => Some("<<"),
+        BinaryAction::Shr => Some(">>"),
+        BinaryAction::LessThan => Some("<"),
+        BinaryAction::LessEqual => Some("<="),
+        BinaryAction::GreaterThan => Some(">"),
+        BinaryAction::GreaterEqual => Some(">="),
+        BinaryAction::Equal => Some("=="),
+        BinaryAction::NotEqual => Some("!="),
+        _ => None,
+    }
+}
+
+fn wire_name(slot: Slot) -> String {
+    format!("w{slot}")
+}
+
+/// Renders `circuit` as a single structural Verilog module: `inputs`/
+/// `outputs` become the port list, one `wire` per slot, and one gate
+/// instance, sub-module instance or `assign` statement per instruction.
+pub fn circuit_to_netlist(circuit: &Circuit) -> Result<String, ExportError> {
+    if circuit.body.is_empty() {
+        return Err(ExportError::EmptyCircuit(circuit.name.0.clone()));
+    }
+
+    let compiled = compile_circuit(circuit);
+
+    let mut out = String::new();
+    let ports: Vec<String> = circuit
+        .inputs
+        .iter()
+        .map(|v| format!("input {}", v.0))
+        .chain(circuit.outputs.iter().map(|v| format!("output {}", v.0)))
+        .collect();
+    out.push_str(&format!("module {}(\n    {}\n);\n", circuit.name.0, ports.join(",\n    ")));
+
+    for slot in 0..compiled.slot_count {
+        out.push_str(&format!("    wire {};\n", wire_name(slot)));
+    }
+
+    let mut gate_count = 0usize;
+    for instruction in &compiled.instructions {
+        match instruction {
+            Instruction::LoadConst(dst, value) => {
+                out.push_str(&format!(
+                    "    assign {} = {}'d{};\n",
+                    wire_name(*dst),
+                    value.width(),
+                    value.as_u64()
+                ));
+            }
+            Instruction::LoadInput(dst, var) => {
+                out.push_str(&format!("    assign {} = {};\n", wire_name(*dst), var.0));
+            }
+            Instruction::Not(dst, a) => {
+                out.push_str(&format!(
+                    "    not g{gate_count}({}, {});\n",
+                    wire_name(*dst),
+                    wire_name(*a)
+                ));
+                gate_count += 1;
+            }
+            Instruction::Binary(dst, action, a, b) => {
+                if let Some(gate) = gate_name(action) {
+                    out.push_str(&format!(
+                        "    {gate} g{gate_count}({}, {}, {});\n",
+                        wire_name(*dst),
+                        wire_name(*a),
+                        wire_name(*b)
+                    ));
+                } else if let Some(op) = operator_symbol(action) {
+                    out.push_str(&format!(
+                        "    assign {} = {} {op} {};\n",
+                        wire_name(*dst),
+                        wire_name(*a),
+                        wire_name(*b)
+                    ));
+                } else if let BinaryAction::Custom(name) = action {
+                    out.push_str(&format!(
+                        "    {} u{gate_count}({}, {}, {});\n",
+                        sub_module_name(name),
+                        wire_name(*a),
+                        wire_name(*b),
+                        wire_name(*dst)
+                    ));
+                } else {
+                    return Err(ExportError::UnsupportedOperator(format!("{action:?}")));
+                }
+                gate_count += 1;
+            }
+            Instruction::Custom(dsts, name, args) => {
+                // Sub-module ports are declared inputs-then-outputs (see the
+                // `ports` list above), so the instantiation must connect them
+                // in that same order.
+                let port_names: Vec<String> = args
+                    .iter()
+                    .chain(dsts.iter())
+                    .map(|s| wire_name(*s))
+                    .collect();
+                out.push_str(&format!(
+                    "    {} u{gate_count}({});\n",
+                    sub_module_name(name),
+                    port_names.join(", ")
+                ));
+                gate_count += 1;
+            }
+            Instruction::BitSelect(dst, src, index) => {
+                out.push_str(&format!(
+                    "    assign {} = {}[{index}];\n",
+                    wire_name(*dst),
+                    wire_name(*src)
+                ));
+            }
+            Instruction::Slice(dst, src, high, low) => {
+                out.push_str(&format!(
+                    "    assign {} = {}[{high}:{low}];\n",
+                    wire_name(*dst),
+                    wire_name(*src)
+                ));
+            }
+            Instruction::Concat(dst, parts) => {
+                let operands: Vec<String> = parts.iter().map(|s| wire_name(*s)).collect();
+                out.push_str(&format!(
+                    "    assign {} = {{{}}};\n",
+                    wire_name(*dst),
+                    operands.join(", ")
+                ));
+            }
+            Instruction::StoreOutput(var, slot) => {
+                out.push_str(&format!("    assign {} = {};\n", var.0, wire_name(*slot)));
+            }
+        }
+    }
+
+    out.push_str("endmodule\n");
+    Ok(out)
+}
+
+fn sub_module_name(var: &Variable) -> &str {
+    &var.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ast::{Assignment, Atom, Expr, NaryAction, Statement};
+
+    fn var(name: &str) -> Variable {
+        Variable(name.to_string(), None)
+    }
+
+    /// A two-circuit design: `top` instantiates `sub`. The sub-module's port
+    /// list is declared inputs-then-outputs (`ports` above), so the
+    /// instantiation line must connect wires in that same order.
+    #[test]
+    fn test_instantiation_port_order_matches_module_header() {
+        let top = Circuit {
+            name: var("top"),
+            inputs: vec![var("a"), var("b")],
+            outputs: vec![var("x")],
+            body: vec![Statement::Assignment(Assignment {
+                into: vec![var("x")],
+                expr: Expr::NaryOp {
+                    params: vec![
+                        Expr::Atom(Atom::Variable(var("a"))),
+                        Expr::Atom(Atom::Variable(var("b"))),
+                    ],
+                    action: NaryAction::Custom(var("sub")),
+                },
+            })],
+        };
+
+        let netlist = circuit_to_netlist(&top).expect("export should succeed");
+
+        let header_line = netlist
+            .lines()
+            .find(|l| l.trim_start().starts_with("module top"))
+            .expect("module header");
+        assert!(header_line.contains("top("));
+
+        let instantiation = netlist
+            .lines()
+            .find(|l| l.contains("sub u0("))
+            .expect("sub-module instantiation line");
+
+        // `a` and `b` feed the sub-module's inputs, `x`'s driving wire is its
+        // output; the inputs must precede the output, matching `sub`'s own
+        // (never-emitted-here, but implied) port order.
+        let open = instantiation.find('(').unwrap();
+        let close = instantiation.find(')').unwrap();
+        let wires: Vec<&str> = instantiation[open + 1..close]
+            .split(", ")
+            .map(str::trim)
+            .collect();
+        assert_eq!(wires.len(), 3, "expected 2 inputs + 1 output: {instantiation}");
+        assert_eq!(wires[2], wire_name(2), "output wire must come last");
+    }
+}
@@ -46,6 +46,17 @@ pub enum BinaryAction {
     Nor,
     Xor,
     Xnor,
+    Add,
+    Sub,
+    Mul,
+    Shl,
+    Shr,
+    LessThan,
+    LessEqual,
+    GreaterThan,
+    GreaterEqual,
+    Equal,
+    NotEqual,
     Custom(Variable),
 }
 
@@ -73,6 +84,23 @@ pub enum Expr {
         action: NaryAction,
     },
     Atom(Atom),
+    /// `a[3]`: a single bit out of a bus.
+    BitSelect {
+        value: Box<Expr>,
+        index: usize,
+        span: Span,
+    },
+    /// `a[7:4]`: an inclusive, high-to-low range out of a bus.
+    Slice {
+        value: Box<Expr>,
+        high: usize,
+        low: usize,
+        span: Span,
+    },
+    /// `a[7:4], b[3:0]`: concatenation, most-significant part first.
+    Concat {
+        parts: Vec<Expr>,
+    },
 }
 
 #[derive(Debug, Eq, PartialEq, Hash)]
@@ -0,0 +1,193 @@
+//! Incremental simulation driver: poke inputs, step time, peek outputs.
+//!
+//! `Simulator::run_test`/`run_all_tests` drive a process to completion in
+//! one call, which is fine for batch test runs but leaves nothing for a
+//! caller that wants to sit in its own event loop — an interactive
+//! debugger, a GUI, or an external test harness driving the circuit one
+//! transaction at a time. `SyncSimClient` is the blocking half of that
+//! split: it drives a step to completion before returning. `AsyncSimClient`
+//! is the non-blocking half, yielding a future that resolves once the step
+//! (and any waveform/export I/O it triggers) has actually happened.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::parse::ast::Variable;
+use crate::sim::simulation::{Simulation, SimulationState};
+use crate::sim::value::Value;
+use crate::sim::SimulationError;
+use crate::time::Duration;
+
+/// Suspends the current task exactly once, then resumes. `AsyncSimClient`
+/// awaits this before doing its (synchronous) work so a call actually
+/// yields to the executor between steps instead of completing the moment
+/// it's first polled.
+struct YieldOnce {
+    polled: bool,
+}
+
+impl YieldOnce {
+    fn new() -> Self {
+        YieldOnce { polled: false }
+    }
+}
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.polled {
+            Poll::Ready(())
+        } else {
+            self.polled = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+/// Blocking client: every call runs to completion before returning.
+pub trait SyncSimClient {
+    /// Sets `var`'s value at the simulation's current instant.
+    fn set_input(&mut self, var: &Variable, value: Value) -> Result<(), SimulationError>;
+
+    /// Advances to the next scheduled event.
+    fn step(&mut self) -> Result<SimulationState, SimulationError>;
+
+    /// Reads `var`'s value as of the current instant.
+    fn read(&self, var: &Variable) -> Option<Value>;
+}
+
+/// Non-blocking client: every call returns a future that resolves once the
+/// corresponding step has actually been driven, for embedding into an async
+/// event loop instead of blocking it.
+pub trait AsyncSimClient {
+    fn set_input<'a>(
+        &'a mut self,
+        var: &'a Variable,
+        value: Value,
+    ) -> Pin<Box<dyn Future<Output = Result<(), SimulationError>> + 'a>>;
+
+    fn step<'a>(&'a mut self) -> Pin<Box<dyn Future<Output = Result<SimulationState, SimulationError>> + 'a>>;
+
+    fn read<'a>(&'a self, var: &'a Variable) -> Pin<Box<dyn Future<Output = Option<Value>> + 'a>>;
+}
+
+/// A running `Simulation` exposed for incremental, caller-driven stepping
+/// rather than being run to completion internally.
+///
+/// Requires `Simulation` to expose `now`, `set_input`, and `read_output` —
+/// new surface for this feature, not just `new`/`step` as used by
+/// `Simulator::run_test`.
+pub struct SyncSimulator {
+    simulation: Simulation,
+}
+
+impl SyncSimulator {
+    pub(crate) fn new(simulation: Simulation) -> Self {
+        SyncSimulator { simulation }
+    }
+
+    /// Steps repeatedly until `duration` has elapsed or the simulation has
+    /// nothing left scheduled, whichever comes first.
+    pub fn advance(&mut self, duration: Duration) -> Result<SimulationState, SimulationError> {
+        let deadline = self.simulation.now() + duration;
+
+        loop {
+            match self.simulation.step()? {
+                SimulationState::Continue if self.simulation.now() < deadline => continue,
+                state => return Ok(state),
+            }
+        }
+    }
+}
+
+impl SyncSimClient for SyncSimulator {
+    fn set_input(&mut self, var: &Variable, value: Value) -> Result<(), SimulationError> {
+        self.simulation.set_input(var, value);
+        Ok(())
+    }
+
+    fn step(&mut self) -> Result<SimulationState, SimulationError> {
+        self.simulation.step()
+    }
+
+    fn read(&self, var: &Variable) -> Option<Value> {
+        self.simulation.read_output(var)
+    }
+}
+
+/// The async counterpart to `SyncSimulator`, wrapping the same `Simulation`
+/// but each call yields to the executor once before doing its work instead
+/// of completing the moment it is first polled.
+pub struct AsyncSimulator {
+    inner: SyncSimulator,
+}
+
+impl AsyncSimulator {
+    pub(crate) fn new(simulation: Simulation) -> Self {
+        AsyncSimulator {
+            inner: SyncSimulator::new(simulation),
+        }
+    }
+}
+
+impl AsyncSimClient for AsyncSimulator {
+    fn set_input<'a>(
+        &'a mut self,
+        var: &'a Variable,
+        value: Value,
+    ) -> Pin<Box<dyn Future<Output = Result<(), SimulationError>> + 'a>> {
+        Box::pin(async move {
+            YieldOnce::new().await;
+            self.inner.set_input(var, value)
+        })
+    }
+
+    fn step<'a>(&'a mut self) -> Pin<Box<dyn Future<Output = Result<SimulationState, SimulationError>> + 'a>> {
+        Box::pin(async move {
+            YieldOnce::new().await;
+            self.inner.step()
+        })
+    }
+
+    fn read<'a>(&'a self, var: &'a Variable) -> Pin<Box<dyn Future<Output = Option<Value>> + 'a>> {
+        Box::pin(async move {
+            YieldOnce::new().await;
+            self.inner.read(var)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::{RawWaker, RawWakerVTable, Waker};
+
+    fn noop_waker() -> Waker {
+        fn clone(_: *const ()) -> RawWaker {
+            raw()
+        }
+        fn noop(_: *const ()) {}
+        fn raw() -> RawWaker {
+            static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        unsafe { Waker::from_raw(raw()) }
+    }
+
+    /// `YieldOnce` is the piece of this module with real, self-contained
+    /// logic: it must return `Pending` on the first poll (so an async
+    /// caller genuinely suspends instead of resolving synchronously) and
+    /// `Ready` on the next.
+    #[test]
+    fn test_yield_once_suspends_exactly_once() {
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut fut = YieldOnce::new();
+
+        assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Pending);
+        assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Ready(()));
+    }
+}
@@ -0,0 +1,350 @@
+//! Lowers a circuit's combinational body to a flat instruction vector: a
+//! linearized, topologically-ordered form of the same `Expr` tree, with
+//! every signal and intermediate sub-expression assigned a slot index.
+//!
+//! This is a lowering pass for `export::netlist`, not a stepping
+//! optimization: `Simulation::step` still tree-walks `Expr` directly and
+//! does not consume `CompiledCircuit`. Feeding this representation into the
+//! interpreter's per-tick evaluation — the speedup a bytecode VM would
+//! normally be for — is a separate, not-yet-started change.
+
+use std::collections::HashMap;
+
+use crate::parse::ast::{
+    Assignment, Atom, BinaryAction, Circuit, Expr, NaryAction, Statement, UnaryAction, Variable,
+};
+use crate::sim::value::Value;
+
+pub type Slot = usize;
+
+#[derive(Debug, Clone)]
+pub enum Instruction {
+    /// Materialize a constant into `dst`.
+    LoadConst(Slot, Value),
+    /// Copy the named input signal into `dst`.
+    LoadInput(Slot, Variable),
+    Not(Slot, Slot),
+    Binary(Slot, BinaryAction, Slot, Slot),
+    /// Invoke a sub-circuit/process by name, reading `args` and writing the
+    /// results into `dsts` (in declaration order).
+    Custom(Vec<Slot>, Variable, Vec<Slot>),
+    /// `dst = src[index]`.
+    BitSelect(Slot, Slot, usize),
+    /// `dst = src[high:low]`.
+    Slice(Slot, Slot, usize, usize),
+    /// `dst = concat(parts)`, most-significant part first.
+    Concat(Slot, Vec<Slot>),
+    /// Publish a slot's value as one of the circuit's named outputs.
+    StoreOutput(Variable, Slot),
+}
+
+/// A circuit lowered to a linear instruction vector plus the slot layout
+/// needed to read it back out.
+#[derive(Debug, Default)]
+pub struct CompiledCircuit {
+    pub instructions: Vec<Instruction>,
+    pub slot_count: usize,
+    pub slots_by_variable: HashMap<Variable, Slot>,
+}
+
+struct Compiler<'a> {
+    circuit: &'a Circuit,
+    /// Maps a variable to the slot holding its current value.
+    slots: HashMap<Variable, Slot>,
+    /// Maps an output variable to the assignment that produces it, so a read
+    /// before that assignment has compiled can pull it in on demand.
+    producers: HashMap<Variable, &'a Assignment>,
+    compiled: std::collections::HashSet<Variable>,
+    instructions: Vec<Instruction>,
+    next_slot: Slot,
+}
+
+impl<'a> Compiler<'a> {
+    fn new(circuit: &'a Circuit) -> Self {
+        let mut producers = HashMap::new();
+        for stmt in &circuit.body {
+            if let Statement::Assignment(assignment) = stmt {
+                for var in &assignment.into {
+                    producers.insert(var.clone(), assignment);
+                }
+            }
+        }
+
+        Compiler {
+            circuit,
+            slots: HashMap::new(),
+            producers,
+            compiled: std::collections::HashSet::new(),
+            instructions: Vec::new(),
+            next_slot: 0,
+        }
+    }
+
+    fn slot_for(&mut self, var: &Variable) -> Slot {
+        if let Some(slot) = self.slots.get(var) {
+            return *slot;
+        }
+        let slot = self.next_slot;
+        self.next_slot += 1;
+        self.slots.insert(var.clone(), slot);
+        slot
+    }
+
+    /// Ensures `var`'s producing assignment (if any) has been compiled,
+    /// which is what gives the final instruction vector its topological
+    /// order: a variable is only read after the instructions that write it
+    /// have been emitted.
+    fn ensure_compiled(&mut self, var: &Variable) {
+        if self.circuit.inputs.contains(var) || self.compiled.contains(var) {
+            return;
+        }
+        if let Some(assignment) = self.producers.get(var).copied() {
+            for out in &assignment.into {
+                self.compiled.insert(out.clone());
+            }
+            self.compile_assignment(assignment);
+        }
+    }
+
+    fn compile_atom(&mut self, atom: &Atom) -> Slot {
+        match atom {
+            Atom::Variable(var) => {
+                self.ensure_compiled(var);
+                if self.circuit.inputs.contains(var) && !self.slots.contains_key(var) {
+                    let slot = self.slot_for(var);
+                    self.instructions
+                        .push(Instruction::LoadInput(slot, var.clone()));
+                    return slot;
+                }
+                self.slot_for(var)
+            }
+            Atom::Constant(constant) => {
+                let slot = self.next_slot;
+                self.next_slot += 1;
+                self.instructions
+                    .push(Instruction::LoadConst(slot, Value::from(constant)));
+                slot
+            }
+            Atom::Expr(expr) => self.compile_expr(expr),
+        }
+    }
+
+    fn compile_expr(&mut self, expr: &Expr) -> Slot {
+        match expr {
+            Expr::Atom(atom) => self.compile_atom(atom),
+            Expr::BinaryOp { a, b, action } => {
+                let sa = self.compile_expr(a);
+                let sb = self.compile_expr(b);
+                let dst = self.next_slot;
+                self.next_slot += 1;
+                self.instructions
+                    .push(Instruction::Binary(dst, clone_action(action), sa, sb));
+                dst
+            }
+            Expr::NaryOp { params, action } => {
+                let args: Vec<Slot> = params.iter().map(|p| self.compile_expr(p)).collect();
+                match action {
+                    NaryAction::UnaryAction(UnaryAction::Not) => {
+                        let dst = self.next_slot;
+                        self.next_slot += 1;
+                        self.instructions.push(Instruction::Not(dst, args[0]));
+                        dst
+                    }
+                    NaryAction::BinaryAction(bin) => {
+                        let dst = self.next_slot;
+                        self.next_slot += 1;
+                        self.instructions
+                            .push(Instruction::Binary(dst, clone_action(bin), args[0], args[1]));
+                        dst
+                    }
+                    NaryAction::Custom(name) => {
+                        let dst = self.next_slot;
+                        self.next_slot += 1;
+                        self.instructions
+                            .push(Instruction::Custom(vec![dst], name.clone(), args));
+                        dst
+                    }
+                }
+            }
+            Expr::BitSelect { value, index, .. } => {
+                let src = self.compile_expr(value);
+                let dst = self.next_slot;
+                self.next_slot += 1;
+                self.instructions
+                    .push(Instruction::BitSelect(dst, src, *index));
+                dst
+            }
+            Expr::Slice { value, high, low, .. } => {
+                let src = self.compile_expr(value);
+                let dst = self.next_slot;
+                self.next_slot += 1;
+                self.instructions
+                    .push(Instruction::Slice(dst, src, *high, *low));
+                dst
+            }
+            Expr::Concat { parts } => {
+                let args: Vec<Slot> = parts.iter().map(|p| self.compile_expr(p)).collect();
+                let dst = self.next_slot;
+                self.next_slot += 1;
+                self.instructions.push(Instruction::Concat(dst, args));
+                dst
+            }
+        }
+    }
+
+    fn compile_assignment(&mut self, assignment: &'a Assignment) {
+        match (&assignment.expr, assignment.into.as_slice()) {
+            (Expr::NaryOp { params, action: NaryAction::Custom(name) }, outs) if outs.len() > 1 => {
+                let args: Vec<Slot> = params.iter().map(|p| self.compile_expr(p)).collect();
+                let dsts: Vec<Slot> = outs.iter().map(|v| self.slot_for(v)).collect();
+                self.instructions
+                    .push(Instruction::Custom(dsts, name.clone(), args));
+            }
+            (expr, outs) => {
+                let src = self.compile_expr(expr);
+                for var in outs {
+                    let dst = self.slot_for(var);
+                    self.slots.insert(var.clone(), dst);
+                    if dst != src {
+                        // Every later read of `var` resolves to `src`'s slot
+                        // directly; no separate move instruction is needed.
+                        self.slots.insert(var.clone(), src);
+                    }
+                }
+            }
+        }
+    }
+
+    fn compile(mut self) -> CompiledCircuit {
+        for stmt in &self.circuit.body {
+            if let Statement::Assignment(assignment) = stmt {
+                let already_compiled = assignment.into.iter().any(|v| self.compiled.contains(v));
+                if !already_compiled {
+                    for var in &assignment.into {
+                        self.compiled.insert(var.clone());
+                    }
+                    self.compile_assignment(assignment);
+                }
+            }
+        }
+
+        for output in &self.circuit.outputs {
+            let slot = self.slot_for(output);
+            self.instructions
+                .push(Instruction::StoreOutput(output.clone(), slot));
+        }
+
+        CompiledCircuit {
+            instructions: self.instructions,
+            slot_count: self.next_slot,
+            slots_by_variable: self.slots,
+        }
+    }
+}
+
+fn clone_action(action: &BinaryAction) -> BinaryAction {
+    match action {
+        BinaryAction::And => BinaryAction::And,
+        BinaryAction::Or => BinaryAction::Or,
+        BinaryAction::Nand => BinaryAction::Nand,
+        BinaryAction::Nor => BinaryAction::Nor,
+        BinaryAction::Xor => BinaryAction::Xor,
+        BinaryAction::Xnor => BinaryAction::Xnor,
+        BinaryAction::Add => BinaryAction::Add,
+        BinaryAction::Sub => BinaryAction::Sub,
+        BinaryAction::Mul => BinaryAction::Mul,
+        BinaryAction::Shl => BinaryAction::Shl,
+        BinaryAction::Shr => BinaryAction::Shr,
+        BinaryAction::LessThan => BinaryAction::LessThan,
+        BinaryAction::LessEqual => BinaryAction::LessEqual,
+        BinaryAction::GreaterThan => BinaryAction::GreaterThan,
+        BinaryAction::GreaterEqual => BinaryAction::GreaterEqual,
+        BinaryAction::Equal => BinaryAction::Equal,
+        BinaryAction::NotEqual => BinaryAction::NotEqual,
+        BinaryAction::Custom(var) => BinaryAction::Custom(var.clone()),
+    }
+}
+
+/// Lowers a single circuit's combinational body to a flat instruction
+/// vector, for `export::netlist` to walk. `Process`/`Test` bodies
+/// additionally interleave `TimeSpec` markers, which this pass doesn't
+/// handle — only a `Circuit`'s combinational assignments are in scope here.
+pub fn compile_circuit(circuit: &Circuit) -> CompiledCircuit {
+    Compiler::new(circuit).compile()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ast::{Assignment, Atom, Expr, NaryAction, UnaryAction};
+
+    fn var(name: &str) -> Variable {
+        Variable(name.to_string(), None)
+    }
+
+    /// A multi-output assignment (`o1, o2 = sub(a, b)`) must lower to exactly
+    /// one `Custom` instruction covering both outputs, not one per output.
+    #[test]
+    fn test_multi_output_assignment_emits_single_custom_instruction() {
+        let circuit = Circuit {
+            name: var("top"),
+            inputs: vec![var("a"), var("b")],
+            outputs: vec![var("o1"), var("o2")],
+            body: vec![Statement::Assignment(Assignment {
+                into: vec![var("o1"), var("o2")],
+                expr: Expr::NaryOp {
+                    params: vec![
+                        Expr::Atom(Atom::Variable(var("a"))),
+                        Expr::Atom(Atom::Variable(var("b"))),
+                    ],
+                    action: NaryAction::Custom(var("sub")),
+                },
+            })],
+        };
+
+        let compiled = compile_circuit(&circuit);
+        let custom_count = compiled
+            .instructions
+            .iter()
+            .filter(|i| matches!(i, Instruction::Custom(..)))
+            .count();
+        assert_eq!(custom_count, 1);
+    }
+
+    /// `ensure_compiled` must emit a variable's producing assignment before
+    /// any instruction that reads it, regardless of body order.
+    #[test]
+    fn test_read_before_producer_pulls_producer_in_first() {
+        let circuit = Circuit {
+            name: var("top"),
+            inputs: vec![var("a")],
+            outputs: vec![var("y")],
+            body: vec![
+                Statement::Assignment(Assignment {
+                    into: vec![var("y")],
+                    expr: Expr::Atom(Atom::Variable(var("mid"))),
+                }),
+                Statement::Assignment(Assignment {
+                    into: vec![var("mid")],
+                    expr: Expr::NaryOp {
+                        params: vec![Expr::Atom(Atom::Variable(var("a")))],
+                        action: NaryAction::UnaryAction(UnaryAction::Not),
+                    },
+                }),
+            ],
+        };
+
+        let compiled = compile_circuit(&circuit);
+        let not_pos = compiled
+            .instructions
+            .iter()
+            .position(|i| matches!(i, Instruction::Not(..)))
+            .expect("Not instruction for `mid`");
+        let store_pos = compiled
+            .instructions
+            .iter()
+            .position(|i| matches!(i, Instruction::StoreOutput(name, _) if name == &var("y")))
+            .expect("StoreOutput for `y`");
+        assert!(not_pos < store_pos);
+    }
+}
@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+/// Where (if anywhere) a simulation run should write its VCD waveform or
+/// structural netlist export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VcdPath {
+    None,
+    Path(PathBuf),
+}
+
+impl Default for VcdPath {
+    fn default() -> Self {
+        VcdPath::None
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+    pub create_vcd: bool,
+    pub vcd_path: VcdPath,
+
+    /// Optional structural-netlist export path, mirroring `vcd_path`.
+    pub export_path: VcdPath,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        SimulationConfig {
+            create_vcd: false,
+            vcd_path: VcdPath::None,
+            export_path: VcdPath::None,
+        }
+    }
+}
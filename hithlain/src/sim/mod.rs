@@ -5,7 +5,10 @@ use simulation::{AssertionError, Simulation, SimulationState};
 
 use crate::parse::desugared_ast::{Process, Program};
 
-use crate::sim::config::SimulationConfig;
+use crate::export::netlist::circuit_to_netlist;
+use crate::export::ExportError;
+use crate::sim::client::{AsyncSimulator, SyncSimulator};
+use crate::sim::config::{SimulationConfig, VcdPath};
 use crate::sim::instantiate::instantiate_program;
 use crate::sim::link::link_process;
 use crate::sim::value::ValueError;
@@ -13,6 +16,8 @@ use crate::vcd::vcd_ast::process_to_vcd_ast;
 use crate::vcd::VcdError;
 use std::rc::Rc;
 
+pub mod client;
+pub mod compile;
 pub mod config;
 pub mod instantiate;
 pub mod instantiated_ast;
@@ -35,6 +40,10 @@ pub enum SimulationError {
     #[error(transparent)]
     #[diagnostic(transparent)]
     VcdError(#[from] VcdError),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ExportError(#[from] ExportError),
 }
 
 pub struct Simulator {
@@ -69,6 +78,30 @@ impl Simulator {
         Ok(())
     }
 
+    /// Renders the named circuit as a structural netlist, for handing off
+    /// to an external synthesis toolchain instead of simulating it.
+    pub fn export_circuit(&self, name: impl AsRef<str>) -> Result<String, SimulationError> {
+        let circuit = self
+            .program
+            .circuits
+            .iter()
+            .find(|c| c.name.0 == name.as_ref())
+            .ok_or_else(|| {
+                ExportError::UnknownSubCircuit(crate::parse::ast::Variable(
+                    name.as_ref().to_string(),
+                    None,
+                ))
+            })?;
+
+        let netlist = circuit_to_netlist(circuit)?;
+
+        if let VcdPath::Path(path) = &self.config.export_path {
+            std::fs::write(path, &netlist).map_err(ExportError::Io)?;
+        }
+
+        Ok(netlist)
+    }
+
     fn execute_process(&self, test: &Rc<Process>) -> Result<(), SimulationError> {
         let instantiated = instantiate_program(test);
 
@@ -85,6 +118,42 @@ impl Simulator {
 
         Ok(())
     }
+
+    /// Builds the named test's `Simulation` but hands it back as a
+    /// `SyncSimulator` instead of driving it to completion, so the caller
+    /// can poke inputs and step time itself.
+    pub fn drive_test(&self, name: impl AsRef<str>) -> Result<SyncSimulator, SimulationError> {
+        let simulation = self.build_simulation(name)?;
+        Ok(SyncSimulator::new(simulation))
+    }
+
+    /// The async counterpart of `drive_test`, for embedding into an event
+    /// loop that can't afford to block on a step.
+    pub fn drive_test_async(&self, name: impl AsRef<str>) -> Result<AsyncSimulator, SimulationError> {
+        let simulation = self.build_simulation(name)?;
+        Ok(AsyncSimulator::new(simulation))
+    }
+
+    fn build_simulation(&self, name: impl AsRef<str>) -> Result<Simulation, SimulationError> {
+        let test = self
+            .program
+            .tests
+            .iter()
+            .find(|t| t.name.0 == name.as_ref())
+            .unwrap_or_else(|| panic!("no such test: {}", name.as_ref()));
+
+        let instantiated = instantiate_program(test);
+
+        let vcd_ast = if self.config.create_vcd {
+            Some(process_to_vcd_ast(&instantiated))
+        } else {
+            None
+        };
+
+        let linked = link_process(instantiated);
+
+        Ok(Simulation::new(linked, &self.config, vcd_ast)?)
+    }
 }
 
 #[cfg(test)]
@@ -179,4 +248,39 @@ mod tests {
         let s = Simulator::new(desugared, config).nice_unwrap_panic();
         s.run_all_tests().nice_unwrap_panic();
     }
+
+    #[test]
+    fn test_bus_add() {
+        let src = "
+        circuit add4: a b -> sum {
+            sum = a + b;
+        }
+
+        test main {
+            sum = add4(a, b);
+
+            at 0ns:
+                a = 6;
+                b = 3;
+
+                assert sum == 9;
+
+            after 5ns:
+                a = 15;
+                b = 2;
+
+                assert sum == 1;
+        }
+        ";
+
+        let lexed = lex(&Source::test(src)).nice_unwrap_panic();
+        let mut parser = Parser::new(lexed);
+
+        let parsed = parser.parse_program().nice_unwrap_panic();
+
+        let desugared = desugar_program(&parsed).nice_unwrap_panic();
+
+        let s = Simulator::new(desugared, SimulationConfig::default()).nice_unwrap_panic();
+        s.run_all_tests().nice_unwrap_panic();
+    }
 }
@@ -1,9 +1,9 @@
 use derive_more::From;
 
 use crate::parse::ast::Constant;
-use crate::sim::value::Value::Bit;
+use crate::sim::value::Value::{Bit, Word};
 use miette::{Diagnostic, NamedSource, SourceSpan};
-use std::ops::{BitAnd, BitOr, BitXor, Not};
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Not, Shl, Shr, Sub};
 use thiserror::Error;
 
 #[derive(Debug, Error, Diagnostic)]
@@ -11,6 +11,100 @@ pub enum ValueError {
     #[error(transparent)]
     #[diagnostic(transparent)]
     AssertionError(#[from] TypeMismatch),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    OutOfRangeSelect(#[from] OutOfRangeSelect),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    InvertedSliceRange(#[from] InvertedSliceRange),
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    WidthOverflow(#[from] WidthOverflow),
+}
+
+#[derive(Error, Debug, Diagnostic)]
+#[error("word width {width} exceeds the {max}-bit maximum")]
+#[diagnostic()]
+pub struct WidthOverflow {
+    pub width: usize,
+    pub max: usize,
+
+    #[source_code]
+    src: NamedSource,
+
+    #[label("here")]
+    span: SourceSpan,
+}
+
+impl WidthOverflow {
+    /// Built when a `Word` would need more bits than `Value` can represent
+    /// internally (it round-trips through `u64` for arithmetic and
+    /// comparisons). Like `TypeMismatch::width_mismatch`, there's no `Span`
+    /// available at this layer, so this placeholder source/span stands in.
+    fn new(width: usize) -> Self {
+        WidthOverflow {
+            width,
+            max: MAX_WORD_WIDTH,
+            src: NamedSource::new("<value>", String::new()),
+            span: (0, 0).into(),
+        }
+    }
+}
+
+#[derive(Error, Debug, Diagnostic)]
+#[error("bit index {index} is out of range for a {width}-bit value")]
+#[diagnostic()]
+pub struct OutOfRangeSelect {
+    pub index: usize,
+    pub width: usize,
+
+    #[source_code]
+    src: NamedSource,
+
+    #[label("out of range here")]
+    span: SourceSpan,
+}
+
+impl OutOfRangeSelect {
+    pub fn new(index: usize, width: usize, src: NamedSource, span: SourceSpan) -> Self {
+        OutOfRangeSelect {
+            index,
+            width,
+            src,
+            span,
+        }
+    }
+}
+
+/// `a[high:low]` where `low > high`: distinct from `OutOfRangeSelect`, which
+/// is about an index falling outside the value's width — here both bounds
+/// can be perfectly in-range, just given in the wrong order.
+#[derive(Error, Debug, Diagnostic)]
+#[error("slice low bound {low} is greater than high bound {high}")]
+#[diagnostic()]
+pub struct InvertedSliceRange {
+    pub low: usize,
+    pub high: usize,
+
+    #[source_code]
+    src: NamedSource,
+
+    #[label("low bound exceeds high bound here")]
+    span: SourceSpan,
+}
+
+impl InvertedSliceRange {
+    pub fn new(low: usize, high: usize, src: NamedSource, span: SourceSpan) -> Self {
+        InvertedSliceRange {
+            low,
+            high,
+            src,
+            span,
+        }
+    }
 }
 
 #[derive(Error, Debug, Diagnostic)]
@@ -24,16 +118,133 @@ pub struct TypeMismatch {
     span: SourceSpan,
 }
 
+impl TypeMismatch {
+    /// Built at the point an operand-width check fails. Operators on `Value`
+    /// don't carry the originating `Span` themselves, so this placeholder
+    /// source/span pair stands in until callers that do have a `Span` thread
+    /// one through.
+    fn width_mismatch() -> Self {
+        TypeMismatch {
+            src: NamedSource::new("<value>", String::new()),
+            span: (0, 0).into(),
+        }
+    }
+}
+
+/// The default width given to a bare numeric literal, since `Constant::Number`
+/// doesn't (yet) carry its own width annotation.
+const DEFAULT_WORD_WIDTH: usize = 64;
+
+/// `Value` round-trips a `Word` through `u64` for arithmetic, comparisons,
+/// and bit-select/slice, so no `Word` may be wider than this.
+const MAX_WORD_WIDTH: usize = 64;
+
 #[derive(From, Debug, Clone)]
 pub enum Value {
     Bit(bool),
+    Word { width: usize, bits: Vec<bool> },
+}
+
+impl Value {
+    /// A multi-bit word holding `value`, truncated/zero-extended to `width`
+    /// bits and stored least-significant-bit first.
+    ///
+    /// Panics if `width` exceeds `MAX_WORD_WIDTH`; callers that can fail
+    /// gracefully instead (e.g. `concat`) check the width themselves and
+    /// return a `WidthOverflow` before ever reaching here.
+    pub fn word(width: usize, value: u64) -> Value {
+        assert!(
+            width <= MAX_WORD_WIDTH,
+            "word width {width} exceeds the {MAX_WORD_WIDTH}-bit maximum"
+        );
+        let bits = (0..width).map(|i| (value >> i) & 1 == 1).collect();
+        Word { width, bits }
+    }
+
+    pub fn width(&self) -> usize {
+        match self {
+            Bit(_) => 1,
+            Word { width, .. } => *width,
+        }
+    }
+
+    pub fn as_u64(&self) -> u64 {
+        match self {
+            Bit(b) => *b as u64,
+            Word { bits, .. } => bits
+                .iter()
+                .enumerate()
+                .fold(0u64, |acc, (i, b)| acc | ((*b as u64) << i)),
+        }
+    }
+
+    fn bits_lsb_first(&self) -> Vec<bool> {
+        match self {
+            Bit(b) => vec![*b],
+            Word { bits, .. } => bits.clone(),
+        }
+    }
+
+    /// `a[index]`: a single bit out of a bus, least-significant-bit first.
+    pub fn bit_select(
+        &self,
+        index: usize,
+        src: NamedSource,
+        span: SourceSpan,
+    ) -> Result<Value, ValueError> {
+        let width = self.width();
+        if index >= width {
+            return Err(OutOfRangeSelect::new(index, width, src, span).into());
+        }
+        Ok(Bit((self.as_u64() >> index) & 1 == 1))
+    }
+
+    /// `a[high:low]`: an inclusive, high-to-low slice out of a bus.
+    pub fn slice(
+        &self,
+        high: usize,
+        low: usize,
+        src: NamedSource,
+        span: SourceSpan,
+    ) -> Result<Value, ValueError> {
+        let width = self.width();
+        if high >= width {
+            return Err(OutOfRangeSelect::new(high, width, src, span).into());
+        }
+        if low > high {
+            return Err(InvertedSliceRange::new(low, high, src, span).into());
+        }
+
+        let new_width = high - low + 1;
+        let mask = if new_width >= 64 {
+            u64::MAX
+        } else {
+            (1u64 << new_width) - 1
+        };
+        Ok(Value::word(new_width, (self.as_u64() >> low) & mask))
+    }
+
+    /// Concatenates `parts`, most-significant part first, matching the
+    /// order they're written in source (`a[7:4], b[3:0]`).
+    pub fn concat(parts: &[Value]) -> Result<Value, ValueError> {
+        let width: usize = parts.iter().map(Value::width).sum();
+        if width > MAX_WORD_WIDTH {
+            return Err(WidthOverflow::new(width).into());
+        }
+
+        let mut bits = Vec::new();
+        for part in parts.iter().rev() {
+            bits.extend(part.bits_lsb_first());
+        }
+        Ok(Word { width, bits })
+    }
 }
 
 impl From<Constant> for Value {
     fn from(c: Constant) -> Self {
         match c {
             Constant::Bit(n) => Value::Bit(n),
-            _ => todo!()
+            Constant::Number(n) => Value::word(DEFAULT_WORD_WIDTH, n),
         }
     }
 }
@@ -42,17 +253,38 @@ impl From<&Constant> for Value {
     fn from(c: &Constant) -> Self {
         match c.clone() {
             Constant::Bit(n) => Value::Bit(n),
-            _ => todo!()
+            Constant::Number(n) => Value::word(DEFAULT_WORD_WIDTH, n),
         }
     }
 }
 
+/// Applies `op` lane-by-lane to two equal-width words, erroring out when the
+/// widths don't match.
+fn zip_lanes(
+    a: Vec<bool>,
+    width: usize,
+    b: Vec<bool>,
+    other_width: usize,
+    op: impl Fn(bool, bool) -> bool,
+) -> Result<Value, ValueError> {
+    if width != other_width {
+        return Err(TypeMismatch::width_mismatch().into());
+    }
+
+    let bits = a.into_iter().zip(b).map(|(x, y)| op(x, y)).collect();
+    Ok(Word { width, bits })
+}
+
 impl BitXor for Value {
     type Output = Result<Value, ValueError>;
 
     fn bitxor(self, rhs: Self) -> Self::Output {
         match (self, rhs) {
             (Bit(a), Bit(b)) => Ok(Bit(a ^ b)),
+            (Word { width, bits: a }, Word { width: w2, bits: b }) => {
+                zip_lanes(a, width, b, w2, |x, y| x ^ y)
+            }
+            _ => Err(TypeMismatch::width_mismatch().into()),
         }
     }
 }
@@ -63,6 +295,10 @@ impl BitAnd for Value {
     fn bitand(self, rhs: Self) -> Self::Output {
         match (self, rhs) {
             (Bit(a), Bit(b)) => Ok(Bit(a & b)),
+            (Word { width, bits: a }, Word { width: w2, bits: b }) => {
+                zip_lanes(a, width, b, w2, |x, y| x & y)
+            }
+            _ => Err(TypeMismatch::width_mismatch().into()),
         }
     }
 }
@@ -73,6 +309,10 @@ impl BitOr for Value {
     fn bitor(self, rhs: Self) -> Self::Output {
         match (self, rhs) {
             (Bit(a), Bit(b)) => Ok(Bit(a | b)),
+            (Word { width, bits: a }, Word { width: w2, bits: b }) => {
+                zip_lanes(a, width, b, w2, |x, y| x | y)
+            }
+            _ => Err(TypeMismatch::width_mismatch().into()),
         }
     }
 }
@@ -83,6 +323,249 @@ impl Not for Value {
     fn not(self) -> Self::Output {
         match self {
             Bit(a) => Ok(Bit(!a)),
+            Word { width, bits } => Ok(Word {
+                width,
+                bits: bits.into_iter().map(|b| !b).collect(),
+            }),
+        }
+    }
+}
+
+impl Add for Value {
+    type Output = Result<Value, ValueError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.width() != rhs.width() {
+            return Err(TypeMismatch::width_mismatch().into());
+        }
+        let width = self.width();
+        let mask = if width >= 64 { u64::MAX } else { (1u64 << width) - 1 };
+        let result = self.as_u64().wrapping_add(rhs.as_u64()) & mask;
+        Ok(Value::word(width, result))
+    }
+}
+
+impl Sub for Value {
+    type Output = Result<Value, ValueError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.width() != rhs.width() {
+            return Err(TypeMismatch::width_mismatch().into());
+        }
+        let width = self.width();
+        let mask = if width >= 64 { u64::MAX } else { (1u64 << width) - 1 };
+        let result = self.as_u64().wrapping_sub(rhs.as_u64()) & mask;
+        Ok(Value::word(width, result))
+    }
+}
+
+impl Mul for Value {
+    type Output = Result<Value, ValueError>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.width() != rhs.width() {
+            return Err(TypeMismatch::width_mismatch().into());
+        }
+        let width = self.width();
+        let mask = if width >= 64 { u64::MAX } else { (1u64 << width) - 1 };
+        let result = self.as_u64().wrapping_mul(rhs.as_u64()) & mask;
+        Ok(Value::word(width, result))
+    }
+}
+
+impl Shl<Value> for Value {
+    type Output = Result<Value, ValueError>;
+
+    fn shl(self, rhs: Self) -> Self::Output {
+        let width = self.width();
+        // `wrapping_shl`/`wrapping_shr` only wrap the *shift amount* mod 64;
+        // a full-width shift (`shift == width`, the common case at
+        // `width == 64`) must still zero the value out, not act as a no-op.
+        let shift = rhs.as_u64();
+        if shift >= width as u64 {
+            return Ok(Value::word(width, 0));
+        }
+        let mask = if width >= 64 { u64::MAX } else { (1u64 << width) - 1 };
+        let result = self.as_u64().wrapping_shl(shift as u32) & mask;
+        Ok(Value::word(width, result))
+    }
+}
+
+impl Shr<Value> for Value {
+    type Output = Result<Value, ValueError>;
+
+    fn shr(self, rhs: Self) -> Self::Output {
+        let width = self.width();
+        let shift = rhs.as_u64();
+        if shift >= width as u64 {
+            return Ok(Value::word(width, 0));
         }
+        let result = self.as_u64().wrapping_shr(shift as u32);
+        Ok(Value::word(width, result))
+    }
+}
+
+impl Value {
+    /// Equal-width comparison, producing a 1-bit result as used by
+    /// `BinaryAction::{LessThan, LessEqual, GreaterThan, GreaterEqual, Equal,
+    /// NotEqual}`.
+    pub fn compare(self, rhs: Self, op: impl Fn(u64, u64) -> bool) -> Result<Value, ValueError> {
+        if self.width() != rhs.width() {
+            return Err(TypeMismatch::width_mismatch().into());
+        }
+        Ok(Bit(op(self.as_u64(), rhs.as_u64())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_add_4bit() {
+        let a = Value::word(4, 6);
+        let b = Value::word(4, 3);
+
+        let sum = (a + b).unwrap();
+        assert_eq!(sum.as_u64(), 9);
+        assert_eq!(sum.width(), 4);
+    }
+
+    #[test]
+    fn test_word_add_wraps_on_overflow() {
+        let a = Value::word(4, 15);
+        let b = Value::word(4, 2);
+
+        let sum = (a + b).unwrap();
+        assert_eq!(sum.as_u64(), 1);
+    }
+
+    #[test]
+    fn test_word_width_mismatch_is_error() {
+        let a = Value::word(4, 1);
+        let b = Value::word(8, 1);
+
+        assert!((a + b).is_err());
+    }
+
+    #[test]
+    fn test_word_bitand_lanes() {
+        let a = Value::word(4, 0b1100);
+        let b = Value::word(4, 0b1010);
+
+        let r = (a & b).unwrap();
+        assert_eq!(r.as_u64(), 0b1000);
+    }
+
+    #[test]
+    fn test_bit_select() {
+        let a = Value::word(4, 0b1010);
+
+        let bit1 = a.bit_select(1, NamedSource::new("<test>", String::new()), (0, 0).into())
+            .unwrap();
+        assert_eq!(bit1.as_u64(), 1);
+
+        let bit0 = a.bit_select(0, NamedSource::new("<test>", String::new()), (0, 0).into())
+            .unwrap();
+        assert_eq!(bit0.as_u64(), 0);
+    }
+
+    #[test]
+    fn test_bit_select_out_of_range() {
+        let a = Value::word(4, 0b1010);
+
+        let result = a.bit_select(4, NamedSource::new("<test>", String::new()), (0, 0).into());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_slice() {
+        let a = Value::word(8, 0b1111_0000);
+
+        let hi = a.slice(7, 4, NamedSource::new("<test>", String::new()), (0, 0).into())
+            .unwrap();
+        assert_eq!(hi.width(), 4);
+        assert_eq!(hi.as_u64(), 0b1111);
+    }
+
+    #[test]
+    fn test_concat() {
+        let hi = Value::word(4, 0b1111);
+        let lo = Value::word(4, 0b0000);
+
+        let combined = Value::concat(&[hi, lo]).unwrap();
+        assert_eq!(combined.width(), 8);
+        assert_eq!(combined.as_u64(), 0b1111_0000);
+    }
+
+    #[test]
+    fn test_concat_over_max_width_is_error() {
+        let a = Value::word(64, u64::MAX);
+        let b = Value::word(64, u64::MAX);
+
+        assert!(Value::concat(&[a, b]).is_err());
+    }
+
+    #[test]
+    fn test_slice_low_greater_than_high_is_distinct_from_out_of_range() {
+        let a = Value::word(8, 0b1111_0000);
+
+        let err = a
+            .slice(2, 5, NamedSource::new("<test>", String::new()), (0, 0).into())
+            .unwrap_err();
+        assert!(matches!(err, ValueError::InvertedSliceRange(_)));
+    }
+
+    #[test]
+    fn test_shl_full_width_shift_zeroes_out() {
+        let a = Value::word(64, u64::MAX);
+        let shifted = (a.clone() << Value::word(64, 64)).unwrap();
+        assert_eq!(shifted.as_u64(), 0);
+
+        let shifted_more = (a << Value::word(64, 100)).unwrap();
+        assert_eq!(shifted_more.as_u64(), 0);
+    }
+
+    #[test]
+    fn test_shr_full_width_shift_zeroes_out() {
+        let a = Value::word(64, u64::MAX);
+        let shifted = (a.clone() >> Value::word(64, 64)).unwrap();
+        assert_eq!(shifted.as_u64(), 0);
+
+        let shifted_more = (a >> Value::word(64, 100)).unwrap();
+        assert_eq!(shifted_more.as_u64(), 0);
+    }
+
+    #[test]
+    fn test_shl_partial_shift_at_64bit_width() {
+        let a = Value::word(64, 1);
+        let shifted = (a << Value::word(64, 4)).unwrap();
+        assert_eq!(shifted.as_u64(), 16);
+    }
+
+    #[test]
+    fn test_sub_wraps_at_64bit_width() {
+        let a = Value::word(64, 0);
+        let b = Value::word(64, 1);
+        let diff = (a - b).unwrap();
+        assert_eq!(diff.as_u64(), u64::MAX);
+    }
+
+    #[test]
+    fn test_mul_wraps_at_64bit_width() {
+        let a = Value::word(64, u64::MAX);
+        let b = Value::word(64, 2);
+        let product = (a * b).unwrap();
+        assert_eq!(product.as_u64(), u64::MAX.wrapping_mul(2));
+    }
+
+    #[test]
+    fn test_word_compare() {
+        let a = Value::word(4, 3);
+        let b = Value::word(4, 5);
+
+        let lt = a.compare(b, |x, y| x < y).unwrap();
+        assert_eq!(lt.as_u64(), 1);
+        assert_eq!(lt.width(), 1);
     }
 }